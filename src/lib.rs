@@ -3,66 +3,313 @@
 
 extern crate chrono;
 
-use chrono::prelude::{DateTime, Local};
-use chrono::Duration;
+use chrono::prelude::{DateTime, Local, TimeZone};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use regex::Regex;
 use semver::Version;
+use serde::{Serialize, Serializer};
 
+use std::collections::HashMap;
 use std::ffi::OsStr;
-use std::fmt;
 use std::fs;
 use std::io;
+use std::path::Path;
+use std::str::FromStr;
 
 // https://semver.org/#is-there-a-suggested-regular-expression-regex-to-check-a-semver-string
 const SEMVER_REGEX: &str = r"(?P<major>0|[1-9]\d*)\.(?P<minor>0|[1-9]\d*)\.(?P<patch>0|[1-9]\d*)(?:-(?P<prerelease>(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+(?P<buildmetadata>[0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?";
 
+// looser grammar for filenames that don't use a full major.minor.patch string (e.g. "mysong 2.als" or
+// "track 1.3.als"); modeled on the version_check crate's major[.minor[.patch]] grammar, with missing minor/patch
+// defaulting to 0. Only consulted when SEMVER_REGEX fails to match. A single bare numeric component (no dotted
+// minor) is deliberately *not* treated as a major version below - see parse_partial_version
+const PARTIAL_VERSION_REGEX: &str = r"\b(?P<major>\d+)(?:\.(?P<minor>\d+))?(?:\.(?P<patch>\d+))?\b";
+
+/// Determines how a project file's version is derived. `FilenameVersionSource` is the original filename-parsing
+/// behavior; `ManifestVersionSource` reads versions from a sidecar manifest instead, for projects that version
+/// inside Ableton's metadata or an external ledger rather than the filename.
+pub trait VersionSource {
+    /// Resolve the version for a file named `name` inside `directory`. The second element of the tuple is true
+    /// when the version was inferred from a looser grammar than the source's primary, strict match.
+    fn resolve(&self, directory: &str, name: &str) -> (Option<Version>, bool);
+}
+
+/// Derives a file's version from its name: the strict `SEMVER_REGEX`, falling back to the looser
+/// `PARTIAL_VERSION_REGEX`. This is the historical, default behavior.
+pub struct FilenameVersionSource {
+    semver_regex: Regex,
+    partial_version_regex: Regex,
+}
+
+impl FilenameVersionSource {
+    pub fn new() -> Self {
+        FilenameVersionSource {
+            semver_regex: Regex::new(SEMVER_REGEX).unwrap(),
+            partial_version_regex: Regex::new(PARTIAL_VERSION_REGEX).unwrap(),
+        }
+    }
+}
+
+impl Default for FilenameVersionSource {
+    fn default() -> Self {
+        FilenameVersionSource::new()
+    }
+}
+
+impl VersionSource for FilenameVersionSource {
+    fn resolve(&self, _directory: &str, name: &str) -> (Option<Version>, bool) {
+        match self.semver_regex.find(name) {
+            Some(version) => (Some(Version::parse(version.as_str()).unwrap()), false),
+            None => match self.partial_version_regex.captures(name).and_then(|captures| parse_partial_version(&captures)) {
+                Some(version) => (Some(version), true),
+                None => (None, false),
+            },
+        }
+    }
+}
+
+/// Parse a `PARTIAL_VERSION_REGEX` match into a `Version`. Returns `None` (rather than panicking) if a numeric
+/// component doesn't fit in a `u64`, e.g. a filename with an absurdly long run of digits.
+///
+/// A dotted match (e.g. "1.3") is treated as major.minor, defaulting a missing patch to 0, the same as a strict
+/// semver string. But a single bare number with no dotted minor (e.g. "take 1.als", "draft 3.als") is far more
+/// likely to be a sequence number than a major version bump, and baseline (unversioned) behavior already counted
+/// the inter-file delta between such files as one continuous session; treating every bare number as a new major
+/// version would turn each sequentially-numbered file into its own session and silently undercount project time.
+/// So a lone numeric component is folded into patch instead, leaving major/minor at 0 and keeping
+/// `is_session_boundary` from treating consecutive sequence-numbered files as a boundary.
+fn parse_partial_version(captures: &regex::Captures) -> Option<Version> {
+    let major = captures.name("major")?.as_str().parse::<u64>().ok()?;
+    match captures.name("minor") {
+        Some(minor) => {
+            let minor = minor.as_str().parse::<u64>().ok()?;
+            let patch = captures.name("patch").map_or(Ok(0), |m| m.as_str().parse::<u64>()).ok()?;
+            Some(Version::new(major, minor, patch))
+        }
+        None => Some(Version::new(0, 0, major)),
+    }
+}
+
+/// Derives a file's version from a sidecar manifest file (e.g. `project.toml` or `version.txt`) in the project
+/// directory, mapping filename stems to declared versions, the way build2's version rule reads a project's
+/// manifest for the authoritative version instead of guessing from names. Each non-comment manifest line is
+/// expected to be `<filename-stem> = <version>`.
+pub struct ManifestVersionSource {
+    versions_by_stem: HashMap<String, Version>,
+}
+
+impl ManifestVersionSource {
+    /// Load version declarations from `manifest_path`.
+    pub fn load(manifest_path: &str) -> Result<Self, io::Error> {
+        let contents = fs::read_to_string(manifest_path)?;
+        let mut versions_by_stem = HashMap::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            if let (Some(stem), Some(version)) = (parts.next(), parts.next()) {
+                let stem = stem.trim().trim_matches('"').to_string();
+                if let Ok(version) = Version::parse(version.trim().trim_matches('"')) {
+                    versions_by_stem.insert(stem, version);
+                }
+            }
+        }
+        Ok(ManifestVersionSource { versions_by_stem })
+    }
+}
+
+impl VersionSource for ManifestVersionSource {
+    fn resolve(&self, _directory: &str, name: &str) -> (Option<Version>, bool) {
+        let stem = Path::new(name).file_stem().and_then(OsStr::to_str).unwrap_or(name);
+        (self.versions_by_stem.get(stem).cloned(), false)
+    }
+}
+
+/// The rendering mode used by `print_project_summary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable tables (the original behavior).
+    Text,
+    /// A single JSON document with per-file entries, per-session subtotals, and a project total.
+    Json,
+    /// One CSV row per project file, for piping into spreadsheets.
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("unrecognized output format: {}", format)),
+        }
+    }
+}
+
 /// Represents a version of a project and all its relevant metadata.
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct ProjectFile {
+    #[serde(serialize_with = "serialize_rfc3339")]
     pub created_datetime: DateTime<Local>,
+    #[serde(serialize_with = "serialize_rfc3339")]
     pub modified_datetime: DateTime<Local>,
+    #[serde(serialize_with = "serialize_duration_millis")]
     pub time_spent: Duration,
     pub name: String,
+    #[serde(serialize_with = "serialize_version")]
     pub version: Option<Version>,
+    /// True when `version` was inferred from the looser `PARTIAL_VERSION_REGEX` grammar (e.g. "2" or "1.3")
+    /// rather than a full major.minor.patch match. This is informational/display-only: `is_session_boundary`
+    /// compares the resulting `Version`'s major/minor fields directly, so strict and inferred versions compare
+    /// identically regardless of this flag.
+    pub version_inferred: bool,
+    /// True when the filesystem had no birth time for this file and `created_datetime` was estimated from
+    /// `modified_datetime` instead.
+    pub created_time_estimated: bool,
 }
 
-impl fmt::Display for ProjectFile {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{: <21} {: <13} {}",
-            self.created_datetime.format("%a %b %e %T"),
-            format_duration(&self.time_spent),
-            self.name,
-        )
+/// Serialize a `DateTime<Local>` as an RFC3339 string.
+fn serialize_rfc3339<S: Serializer>(datetime: &DateTime<Local>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&datetime.to_rfc3339())
+}
+
+/// Serialize a `Duration` as whole milliseconds, the finest precision `format_duration` displays.
+fn serialize_duration_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(duration.num_milliseconds())
+}
+
+/// Serialize an `Option<Version>` as its string form (e.g. "1.2.3") or null.
+fn serialize_version<S: Serializer>(version: &Option<Version>, serializer: S) -> Result<S::Ok, S::Error> {
+    match version {
+        Some(version) => serializer.serialize_str(&version.to_string()),
+        None => serializer.serialize_none(),
     }
 }
 
-/// Build and return a vector of ProjectFiles in some directory, sorted by creation timestamp.
-fn initialize_project_files(directory: String, project_file_suffix: String) -> Result<Vec<ProjectFile>, io::Error> {
-    let semver_regex: Regex = Regex::new(SEMVER_REGEX).unwrap();
+/// The minimum width of the start/end time columns: the original hardcoded column width, which fits the default
+/// strftime format (`DEFAULT_TIME_FORMAT` in main.rs, 19 characters) with two characters to spare. Wider rendered
+/// formats (e.g. `--iso`'s RFC3339, which runs 25-35 characters depending on fractional seconds) widen the column
+/// further so columns stay aligned against the header.
+const MIN_TIMESTAMP_COLUMN_WIDTH: usize = 21;
+
+/// The width to render the start/end time columns at for the given `time_format`, wide enough to fit a formatted
+/// timestamp without overflowing into the next column.
+fn timestamp_column_width(time_format: &str) -> usize {
+    Local::now().format(time_format).to_string().len().max(MIN_TIMESTAMP_COLUMN_WIDTH)
+}
+
+/// Render a single project file row: start time, end time, duration, and name, with any inferred/estimated
+/// annotations. `end_datetime` is the created_datetime of the next file, or modified_datetime for the last file.
+/// `time_format` is a chrono strftime string applied to both timestamps; `timestamp_width` sizes their columns
+/// (see `timestamp_column_width`).
+fn format_project_file_row(
+    project_file: &ProjectFile,
+    end_datetime: DateTime<Local>,
+    time_format: &str,
+    timestamp_width: usize,
+) -> String {
+    format!(
+        "{: <width$} {: <width$} {: <13} {}{}{}",
+        project_file.created_datetime.format(time_format),
+        end_datetime.format(time_format),
+        format_duration(&project_file.time_spent),
+        project_file.name,
+        if project_file.version_inferred { " (version inferred)" } else { "" },
+        if project_file.created_time_estimated { " (created time estimated)" } else { "" },
+        width = timestamp_width,
+    )
+}
+
+/// The created_datetime of the next file, or modified_datetime for the last file, for each provided project file.
+fn end_datetimes(project_files: &[ProjectFile]) -> Vec<DateTime<Local>> {
+    project_files
+        .iter()
+        .enumerate()
+        .map(|(idx, project_file)| {
+            project_files.get(idx + 1).map_or(project_file.modified_datetime, |next| next.created_datetime)
+        })
+        .collect()
+}
+
+/// Parse a `--changed-within`/`--changed-before` value as either a relative duration measured back from now
+/// (e.g. "2weeks", "1d", "10min") or an absolute local date/datetime (e.g. "2023-01-15", "2023-01-15 14:30:00").
+fn parse_time_bound(value: &str) -> Result<DateTime<Local>, io::Error> {
+    if let Ok(relative_duration) = humantime::parse_duration(value) {
+        let relative_duration =
+            Duration::from_std(relative_duration).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        return Ok(Local::now() - relative_duration);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid date: {}", value)))?;
+        return Local
+            .from_local_datetime(&midnight)
+            .single()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("ambiguous local date: {}", value)));
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Local
+            .from_local_datetime(&datetime)
+            .single()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("ambiguous local datetime: {}", value)));
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("unable to parse \"{}\" as a duration or date", value),
+    ))
+}
+
+/// Build and return a vector of ProjectFiles in some directory, sorted by creation timestamp. Files whose
+/// modified time falls outside `[changed_within, changed_before]` (when provided) are excluded.
+fn initialize_project_files(
+    directory: String,
+    project_file_suffix: String,
+    changed_within: Option<String>,
+    changed_before: Option<String>,
+    version_source: &dyn VersionSource,
+) -> Result<Vec<ProjectFile>, io::Error> {
     let mut project_files: Vec<ProjectFile> = Vec::new();
 
+    // lower/upper bounds on modified_datetime, parsed up front so a bad value fails fast
+    let changed_within_lower = changed_within.as_deref().map(parse_time_bound).transpose()?;
+    let changed_before_upper = changed_before.as_deref().map(parse_time_bound).transpose()?;
+
     // initialize project_files with all valid files found in provided directory
-    for entry in fs::read_dir(directory)? {
+    for entry in fs::read_dir(&directory)? {
         let entry = entry?;
         let path = entry.path();
         let is_file = path.is_file();
         let is_valid_filetype = str::ends_with(path.to_str().unwrap(), &project_file_suffix);
         if is_file && is_valid_filetype {
             let name = path.file_name().and_then(OsStr::to_str).unwrap();
-            // extract semantic version from file name if one can be found
-            let version: Option<Version> = match semver_regex.find(name) {
-                Some(version) => Some(Version::parse(version.as_str()).unwrap()),
-                None => None,
-            };
+            let (version, version_inferred) = version_source.resolve(&directory, name);
+            let metadata = entry.metadata()?;
+            let modified_datetime = DateTime::<Local>::from(metadata.modified()?);
+            let (created_datetime, created_time_estimated) =
+                resolve_created_datetime(metadata.created(), modified_datetime);
             let project_file = ProjectFile {
-                created_datetime: DateTime::<Local>::from(entry.metadata()?.created()?),
-                modified_datetime: DateTime::<Local>::from(entry.metadata()?.modified()?),
+                created_datetime,
+                modified_datetime,
                 time_spent: Duration::zero(), // initialize with zero value, calculated after all files are initialized
                 name: name.to_string(),
                 version,
+                version_inferred,
+                created_time_estimated,
             };
+
+            // skip files outside the requested date range
+            if changed_within_lower.map_or(false, |lower| project_file.modified_datetime < lower)
+                || changed_before_upper.map_or(false, |upper| project_file.modified_datetime > upper)
+            {
+                continue;
+            }
+
             project_files.push(project_file);
         }
     }
@@ -74,7 +321,28 @@ fn initialize_project_files(directory: String, project_file_suffix: String) -> R
     Ok(project_files)
 }
 
-/// Calculate time spent on the provided project files.
+/// Resolve a file's created_datetime from its filesystem birth time, falling back to `modified_datetime` (and
+/// flagging the fallback via the second element) rather than aborting the scan. Not all filesystems track a
+/// birth time (e.g. most Linux filesystems), so `created` is `metadata.created()`'s result.
+fn resolve_created_datetime(
+    created: Result<std::time::SystemTime, io::Error>,
+    modified_datetime: DateTime<Local>,
+) -> (DateTime<Local>, bool) {
+    match created {
+        Ok(created) => (DateTime::<Local>::from(created), false),
+        Err(_) => (modified_datetime, true),
+    }
+}
+
+/// Calculate time spent on the provided project files. When a file's `created_time_estimated` is set,
+/// `created_datetime` already equals `modified_datetime`, so the deltas below naturally fall back to comparing
+/// modified times instead of producing a bogus zero-length creation-to-creation span. In particular, the very
+/// last file in a batch falls all the way back to zero `time_spent` when its birth time is missing: there's no
+/// next file to derive a creation-to-creation span from, and no other signal (like a previous save) to estimate
+/// one, so zero is the honest answer rather than a guess. The file is still listed (with
+/// `created_time_estimated` annotating the row) and still contributes its unestimated delta if it isn't the
+/// trailing file, so the zero only ever affects that one trailing entry, not the project total's accuracy for
+/// every other file.
 fn calculate_time_spent(project_files: &mut Vec<ProjectFile>, max_time_between_saves: Duration) {
     for i in 0..project_files.len() {
         // start with delta between modified time and creation time
@@ -97,7 +365,9 @@ fn calculate_time_spent(project_files: &mut Vec<ProjectFile>, max_time_between_s
     }
 }
 
-/// Check two (optional) versions to see if they represent a session boundary.
+/// Check two (optional) versions to see if they represent a session boundary. Compares `Version` major/minor
+/// fields directly, so it doesn't matter whether either version was a strict filename match or inferred from
+/// `PARTIAL_VERSION_REGEX` (see `ProjectFile::version_inferred`) — both compare identically.
 fn is_session_boundary(
     current_version: &Option<Version>,
     next_version: &Option<Version>,
@@ -146,8 +416,14 @@ fn sum_project_durations(project_files: &[ProjectFile]) -> Duration {
 }
 
 /// Print to stdout the time spent on each project file, as well as a summary for the session (minor version)
-/// if applicable.
-fn print_session_summary(project_files: &[ProjectFile]) {
+/// if applicable. `end_datetimes` must be the same length as `project_files`. `timestamp_width` sizes the
+/// start/end time columns (see `timestamp_column_width`).
+fn print_session_summary(
+    project_files: &[ProjectFile],
+    end_datetimes: &[DateTime<Local>],
+    time_format: &str,
+    timestamp_width: usize,
+) {
     if project_files.is_empty() {
         return;
     }
@@ -160,19 +436,39 @@ fn print_session_summary(project_files: &[ProjectFile]) {
             format_duration(&sum_project_durations(project_files))
         );
     }
-    for project_file in project_files {
-        println!("{}", project_file);
+    for (project_file, end_datetime) in project_files.iter().zip(end_datetimes) {
+        println!("{}", format_project_file_row(project_file, *end_datetime, time_format, timestamp_width));
     }
     println!() // extra newline for readability
 }
 
-/// Find all project files in the given directory and calculate time spent on each.
+/// Find all project files in the given directory and calculate time spent on each. `changed_within` and
+/// `changed_before` optionally restrict results to files modified inside that window; each accepts either a
+/// relative duration ("2weeks", "1d", "10min") or an absolute date ("2023-01-15", "2023-01-15 14:30:00").
+/// `version_manifest`, if provided, is the path to a sidecar manifest file used to derive versions instead of
+/// parsing them from filenames (see `ManifestVersionSource`).
 pub fn scan_project_files(
     directory: String,
     project_file_suffix: String,
     max_minutes_between_saves: i64,
+    changed_within: Option<String>,
+    changed_before: Option<String>,
+    version_manifest: Option<String>,
 ) -> Result<Vec<ProjectFile>, io::Error> {
-    let mut project_files: Vec<ProjectFile> = initialize_project_files(directory, project_file_suffix)?;
+    let version_source: Box<dyn VersionSource> = match version_manifest {
+        Some(manifest_path) => Box::new(ManifestVersionSource::load(&manifest_path)?),
+        None => Box::new(FilenameVersionSource::new()),
+    };
+
+    // filtering happens inside initialize_project_files, before calculate_time_spent runs, so inter-file deltas
+    // are only computed across the retained set
+    let mut project_files: Vec<ProjectFile> = initialize_project_files(
+        directory,
+        project_file_suffix,
+        changed_within,
+        changed_before,
+        version_source.as_ref(),
+    )?;
 
     // if max_minutes_between_saves is <= 0, effectively disable the max time check by using Duration's max value
     let max_time_between_saves: Duration = if max_minutes_between_saves > 0 {
@@ -186,30 +482,74 @@ pub fn scan_project_files(
     Ok(project_files)
 }
 
+/// A per-session subtotal, grouped by major.minor version, modeled on the cache-entry style used by tools like
+/// cargo-debstatus: a flat struct carrying the session's version and summed duration alongside its files so it
+/// serializes cleanly without downstream tools having to re-derive session boundaries.
+#[derive(Serialize)]
+struct SessionSummary<'a> {
+    version: Option<String>,
+    time_spent_ms: i64,
+    files: &'a [ProjectFile],
+}
+
+/// The JSON document emitted by `print_project_summary` in `OutputFormat::Json` mode.
+#[derive(Serialize)]
+struct ProjectSummaryDocument<'a> {
+    sessions: Vec<SessionSummary<'a>>,
+    total_time_spent_ms: i64,
+}
+
+/// Index ranges splitting `project_files` into sessions, one range per major.minor grouping, the same way the
+/// text renderer groups rows.
+fn session_ranges(project_files: &[ProjectFile]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut current_version_idx: usize = 0;
+    for idx in 0..project_files.len() {
+        let is_last = idx == project_files.len() - 1;
+        let is_boundary =
+            !is_last && is_session_boundary(&project_files[idx].version, &project_files[idx + 1].version, false);
+        if is_boundary || is_last {
+            ranges.push(current_version_idx..idx + 1);
+            current_version_idx = idx + 1;
+        }
+    }
+    ranges
+}
+
 /// Print to stdout a summary of time spent on each project file, time spent on each session/minor version
-/// (if applicable), and total time spent on the project.
-pub fn print_project_summary(project_files: &[ProjectFile]) {
+/// (if applicable), and total time spent on the project, rendered in the requested `OutputFormat`. `time_format`
+/// is a chrono strftime string honored by `OutputFormat::Text`.
+///
+/// Empty-input contract: when `project_files` is empty, `OutputFormat::Text` prints "No project files found",
+/// `OutputFormat::Json` emits a valid, empty document (`{"sessions":[],"total_time_spent_ms":0}`), and
+/// `OutputFormat::Csv` writes just the header row. The machine-readable formats always emit a well-formed,
+/// parseable document rather than nothing, so downstream consumers don't need to special-case an empty project.
+pub fn print_project_summary(project_files: &[ProjectFile], format: OutputFormat, time_format: &str) {
+    match format {
+        OutputFormat::Text => print_project_summary_text(project_files, time_format),
+        OutputFormat::Json => print_project_summary_json(project_files),
+        OutputFormat::Csv => print_project_summary_csv(project_files),
+    }
+}
+
+/// Render `project_files` as the original human-readable tables.
+fn print_project_summary_text(project_files: &[ProjectFile], time_format: &str) {
     if project_files.is_empty() {
         println!("No project files found");
         return;
     }
 
-    println!("{: <21} {: <13} Name", "Start time", "Duration");
-    let mut current_version: &Option<Version>;
-    let mut current_version_idx: usize = 0;
-    for idx in 0..project_files.len() {
-        current_version = &project_files[idx].version;
-        if idx < project_files.len() - 1 {
-            let next_version = &project_files[idx + 1].version;
-            // print summary at session boundaries
-            if is_session_boundary(current_version, next_version, false) {
-                print_session_summary(&project_files[current_version_idx..idx + 1]);
-                current_version_idx = idx + 1;
-            }
-        } else {
-            // print last session summary
-            print_session_summary(&project_files[current_version_idx..]);
-        }
+    let end_datetimes = end_datetimes(project_files);
+    let timestamp_width = timestamp_column_width(time_format);
+    println!(
+        "{: <width$} {: <width$} {: <13} Name",
+        "Start time",
+        "End time",
+        "Duration",
+        width = timestamp_width
+    );
+    for range in session_ranges(project_files) {
+        print_session_summary(&project_files[range.clone()], &end_datetimes[range], time_format, timestamp_width);
     }
     println!(
         "Total project time\n{}",
@@ -217,6 +557,60 @@ pub fn print_project_summary(project_files: &[ProjectFile]) {
     );
 }
 
+/// Render `project_files` as a single JSON document of sessions and a project total.
+fn print_project_summary_json(project_files: &[ProjectFile]) {
+    let sessions: Vec<SessionSummary> = session_ranges(project_files)
+        .into_iter()
+        .map(|range| {
+            let files = &project_files[range];
+            SessionSummary {
+                version: files[0].version.as_ref().map(|version| format!("{}.{}", version.major, version.minor)),
+                time_spent_ms: sum_project_durations(files).num_milliseconds(),
+                files,
+            }
+        })
+        .collect();
+    let document = ProjectSummaryDocument {
+        total_time_spent_ms: sum_project_durations(project_files).num_milliseconds(),
+        sessions,
+    };
+
+    match serde_json::to_string_pretty(&document) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("failed to serialize project summary: {}", e),
+    }
+}
+
+/// Render `project_files` as one CSV row per file. Always writes the header row, even when `project_files` is
+/// empty, so downstream consumers can rely on a fixed column set rather than special-casing an empty document.
+fn print_project_summary_csv(project_files: &[ProjectFile]) {
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    if project_files.is_empty() {
+        let header = [
+            "created_datetime",
+            "modified_datetime",
+            "time_spent",
+            "name",
+            "version",
+            "version_inferred",
+            "created_time_estimated",
+        ];
+        if let Err(e) = writer.write_record(header) {
+            eprintln!("failed to write csv header: {}", e);
+            return;
+        }
+    }
+    for project_file in project_files {
+        if let Err(e) = writer.serialize(project_file) {
+            eprintln!("failed to write csv row: {}", e);
+            return;
+        }
+    }
+    if let Err(e) = writer.flush() {
+        eprintln!("failed to flush csv writer: {}", e);
+    }
+}
+
 #[cfg(test)]
 mod lib_tests {
     use super::*;
@@ -241,6 +635,8 @@ mod lib_tests {
             time_spent: Duration::zero(),
             name: String::from("abletime 0.1.0.als"),
             version: Version::parse("0.1.0").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
         };
         project_files.push(project_file_a);
         calculate_time_spent(&mut project_files, Duration::max_value());
@@ -255,6 +651,8 @@ mod lib_tests {
             time_spent: Duration::zero(),
             name: String::from("abletime 0.1.1.als"),
             version: Version::parse("0.1.1").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
         };
         project_files.push(project_file_b);
         calculate_time_spent(&mut project_files, Duration::max_value());
@@ -262,6 +660,96 @@ mod lib_tests {
         assert_eq!(project_files[1].time_spent, modified_datetime_b - created_datetime_b);
     }
 
+    #[test]
+    fn test_calculate_time_spent_trailing_file_with_estimated_created_time_is_zero() {
+        // the last file in a batch has no birth time, so created_datetime falls back to modified_datetime
+        // (created_time_estimated); with no next file to derive a span from, its time_spent is honestly zero
+        let created_datetime_a = Local::now();
+        let modified_datetime_a = created_datetime_a + Duration::seconds(1);
+        let project_file_a = ProjectFile {
+            created_datetime: created_datetime_a,
+            modified_datetime: modified_datetime_a,
+            time_spent: Duration::zero(),
+            name: String::from("abletime 0.1.0.als"),
+            version: Version::parse("0.1.0").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
+        };
+        let modified_datetime_b = modified_datetime_a + Duration::seconds(5);
+        let project_file_b = ProjectFile {
+            created_datetime: modified_datetime_b,
+            modified_datetime: modified_datetime_b,
+            time_spent: Duration::zero(),
+            name: String::from("abletime 0.1.1.als"),
+            version: Version::parse("0.1.1").ok(),
+            version_inferred: false,
+            created_time_estimated: true,
+        };
+        let mut project_files = vec![project_file_a, project_file_b];
+        calculate_time_spent(&mut project_files, Duration::max_value());
+        assert_eq!(project_files[1].time_spent, Duration::zero());
+    }
+
+    #[test]
+    fn test_resolve_created_datetime_present() {
+        let modified_datetime = Local::now();
+        let birth_time = std::time::SystemTime::now() - std::time::Duration::from_secs(60);
+        let (created_datetime, created_time_estimated) = resolve_created_datetime(Ok(birth_time), modified_datetime);
+        assert_eq!(created_datetime, DateTime::<Local>::from(birth_time));
+        assert_eq!(created_time_estimated, false);
+    }
+
+    #[test]
+    fn test_resolve_created_datetime_falls_back_when_birth_time_unavailable() {
+        let modified_datetime = Local::now();
+        let (created_datetime, created_time_estimated) =
+            resolve_created_datetime(Err(io::Error::new(io::ErrorKind::Unsupported, "no birth time")), modified_datetime);
+        assert_eq!(created_datetime, modified_datetime);
+        assert_eq!(created_time_estimated, true);
+    }
+
+    #[test]
+    fn test_end_datetimes() {
+        let created_datetime_a = Local::now();
+        let modified_datetime_a = created_datetime_a + Duration::seconds(1);
+        let project_file_a = ProjectFile {
+            created_datetime: created_datetime_a,
+            modified_datetime: modified_datetime_a,
+            time_spent: Duration::zero(),
+            name: String::from("abletime 0.1.0.als"),
+            version: Version::parse("0.1.0").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
+        };
+        let created_datetime_b = modified_datetime_a + Duration::seconds(1);
+        let modified_datetime_b = created_datetime_b + Duration::seconds(1);
+        let project_file_b = ProjectFile {
+            created_datetime: created_datetime_b,
+            modified_datetime: modified_datetime_b,
+            time_spent: Duration::zero(),
+            name: String::from("abletime 0.1.1.als"),
+            version: Version::parse("0.1.1").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
+        };
+        let project_files = vec![project_file_a, project_file_b];
+
+        let result = end_datetimes(&project_files);
+        // the first file's end time is the next file's created_datetime
+        assert_eq!(result[0], created_datetime_b);
+        // the last file's end time is its own modified_datetime
+        assert_eq!(result[1], modified_datetime_b);
+    }
+
+    #[test]
+    fn test_timestamp_column_width_widens_for_longer_formats() {
+        // the default text format fits within the original hardcoded column width
+        assert_eq!(timestamp_column_width("%a %b %e %T"), MIN_TIMESTAMP_COLUMN_WIDTH);
+        // --iso's RFC3339 format (chrono's "%+") is wider, and must widen the column so rows stay aligned
+        // against the "Start time"/"End time" header instead of overflowing it
+        assert!(timestamp_column_width("%+") > MIN_TIMESTAMP_COLUMN_WIDTH);
+    }
+
     #[test]
     fn test_sum_project_durations() {
         let mut project_files: Vec<ProjectFile> = Vec::new();
@@ -273,6 +761,8 @@ mod lib_tests {
             time_spent: Duration::seconds(1),
             name: String::from("abletime 0.1.0.als"),
             version: Version::parse("0.1.0").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
         };
         project_files.push(project_file_a);
         let project_file_b = ProjectFile {
@@ -281,11 +771,122 @@ mod lib_tests {
             time_spent: Duration::seconds(10),
             name: String::from("abletime 0.1.1.als"),
             version: Version::parse("0.1.1").ok(),
+            version_inferred: false,
+            created_time_estimated: false,
         };
         project_files.push(project_file_b);
         assert_eq!(sum_project_durations(&project_files), Duration::seconds(11));
     }
 
+    #[test]
+    fn test_parse_time_bound_relative_duration() {
+        let before = Local::now() - Duration::weeks(2);
+        let parsed = parse_time_bound("2weeks").unwrap();
+        let after = Local::now() - Duration::weeks(2);
+        assert!(parsed >= before - Duration::seconds(1) && parsed <= after + Duration::seconds(1));
+
+        let before = Local::now() - Duration::minutes(10);
+        let parsed = parse_time_bound("10min").unwrap();
+        let after = Local::now() - Duration::minutes(10);
+        assert!(parsed >= before - Duration::seconds(1) && parsed <= after + Duration::seconds(1));
+    }
+
+    #[test]
+    fn test_parse_time_bound_absolute_date() {
+        let parsed = parse_time_bound("2023-01-15").unwrap();
+        let expected = Local
+            .from_local_datetime(&NaiveDate::from_ymd_opt(2023, 1, 15).unwrap().and_hms_opt(0, 0, 0).unwrap())
+            .unwrap();
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_time_bound_absolute_datetime() {
+        let parsed = parse_time_bound("2023-01-15 14:30:00").unwrap();
+        let expected = Local
+            .from_local_datetime(&NaiveDate::from_ymd_opt(2023, 1, 15).unwrap().and_hms_opt(14, 30, 0).unwrap())
+            .unwrap();
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_time_bound_rejects_unparseable_value() {
+        assert!(parse_time_bound("not a duration or date").is_err());
+    }
+
+    #[test]
+    fn test_filename_version_source_strict() {
+        let source = FilenameVersionSource::new();
+        assert_eq!(source.resolve(".", "abletime 0.1.0.als"), (Version::parse("0.1.0").ok(), false));
+    }
+
+    #[test]
+    fn test_filename_version_source_partial() {
+        let source = FilenameVersionSource::new();
+        // a dotted major.minor match is treated as a real version
+        assert_eq!(source.resolve(".", "track 1.3.als"), (Some(Version::new(1, 3, 0)), true));
+        // a single bare number is a sequence number, not a major version bump - folded into patch
+        assert_eq!(source.resolve(".", "mysong 2.als"), (Some(Version::new(0, 0, 2)), true));
+    }
+
+    #[test]
+    fn test_filename_version_source_no_version() {
+        let source = FilenameVersionSource::new();
+        assert_eq!(source.resolve(".", "mysong.als"), (None, false));
+    }
+
+    #[test]
+    fn test_filename_version_source_sequence_numbered_files_are_not_session_boundaries() {
+        // regression test: "take 1.als", "take 2.als", "draft 3.als" are a common non-semver naming scheme.
+        // Before this fix, each bare number was inferred as a major version, so is_session_boundary treated
+        // every file as starting a new session and calculate_time_spent stopped counting the inter-file delta
+        // that baseline (fully unversioned) behavior did count - silently undercounting project time.
+        let source = FilenameVersionSource::new();
+        let (take_1, _) = source.resolve(".", "take 1.als");
+        let (take_2, _) = source.resolve(".", "take 2.als");
+        assert_eq!(is_session_boundary(&take_1, &take_2, false), false);
+    }
+
+    #[test]
+    fn test_output_format_from_str() {
+        assert_eq!(OutputFormat::from_str("text"), Ok(OutputFormat::Text));
+        assert_eq!(OutputFormat::from_str("JSON"), Ok(OutputFormat::Json));
+        assert_eq!(OutputFormat::from_str("Csv"), Ok(OutputFormat::Csv));
+        assert!(OutputFormat::from_str("yaml").is_err());
+    }
+
+    #[test]
+    fn test_manifest_version_source_load_and_resolve() {
+        let manifest_path = std::env::temp_dir().join(format!(
+            "abletime_test_manifest_{:?}.txt",
+            std::thread::current().id()
+        ));
+        fs::write(
+            &manifest_path,
+            "# a leading comment\n[section header]\n\nmysong = 1.2.3\n\"quoted song\" = \"2.0.0\"\nmalformed line\n",
+        )
+        .unwrap();
+
+        let source = ManifestVersionSource::load(manifest_path.to_str().unwrap()).unwrap();
+        assert_eq!(source.resolve(".", "mysong.als"), (Version::parse("1.2.3").ok(), false));
+        assert_eq!(source.resolve(".", "quoted song.als"), (Version::parse("2.0.0").ok(), false));
+        assert_eq!(source.resolve(".", "unlisted.als"), (None, false));
+
+        fs::remove_file(&manifest_path).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_version_source_load_missing_file() {
+        assert!(ManifestVersionSource::load("/nonexistent/abletime-manifest.txt").is_err());
+    }
+
+    #[test]
+    fn test_parse_partial_version_overflow_falls_back_to_none() {
+        let partial_version_regex = Regex::new(PARTIAL_VERSION_REGEX).unwrap();
+        let captures = partial_version_regex.captures("track 99999999999999999999.als").unwrap();
+        assert_eq!(parse_partial_version(&captures), None);
+    }
+
     #[test]
     fn test_is_session_boundary() {
         // session boundary: the next project file is one minor version greater than the current
@@ -1,10 +1,13 @@
 extern crate abletime;
 extern crate clap;
 
+use abletime::OutputFormat;
 use clap::Clap;
 
 const ABLETON_SUFFIX: &str = ".als";
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_TIME_FORMAT: &str = "%a %b %e %T";
+const ISO_TIME_FORMAT: &str = "%+";
 
 #[derive(Clap, Debug)]
 #[clap(version = VERSION)]
@@ -21,20 +24,54 @@ struct Opts {
     /// Values <= 0 will disable this feature
     #[clap(short, long, default_value = "60")]
     max_minutes_between_saves: i64,
+
+    /// Output format for the project summary
+    #[clap(short, long, default_value = "text", possible_values = &["text", "json", "csv"])]
+    format: OutputFormat,
+
+    /// Only include files modified within this long ago. Accepts a relative duration (e.g. "2weeks", "1d",
+    /// "10min") or an absolute date (e.g. "2023-01-15", "2023-01-15 14:30:00")
+    #[clap(long)]
+    changed_within: Option<String>,
+
+    /// Only include files modified before this long ago. Accepts a relative duration (e.g. "2weeks", "1d",
+    /// "10min") or an absolute date (e.g. "2023-01-15", "2023-01-15 14:30:00")
+    #[clap(long)]
+    changed_before: Option<String>,
+
+    /// chrono strftime format string used for displayed timestamps. Ignored if --iso is set
+    #[clap(long, default_value = DEFAULT_TIME_FORMAT)]
+    time_format: String,
+
+    /// Shortcut for --time-format with an RFC 3339 / ISO 8601 format
+    #[clap(long)]
+    iso: bool,
+
+    /// Path to a sidecar manifest file (e.g. project.toml or version.txt) mapping filename stems to versions,
+    /// used instead of parsing versions from filenames
+    #[clap(long)]
+    version_manifest: Option<String>,
 }
 
 fn main() -> std::io::Result<()> {
     let opts: Opts = Opts::parse();
 
-    let project_files = match abletime::scan_project_files(opts.directory, opts.suffix, opts.max_minutes_between_saves)
-    {
+    let project_files = match abletime::scan_project_files(
+        opts.directory,
+        opts.suffix,
+        opts.max_minutes_between_saves,
+        opts.changed_within,
+        opts.changed_before,
+        opts.version_manifest,
+    ) {
         Ok(project_files) => project_files,
         Err(e) => {
             println!("{}", e);
             std::process::exit(1)
         }
     };
-    abletime::print_project_summary(&project_files);
+    let time_format = if opts.iso { ISO_TIME_FORMAT } else { &opts.time_format };
+    abletime::print_project_summary(&project_files, opts.format, time_format);
 
     Ok(())
 }